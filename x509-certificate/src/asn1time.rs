@@ -3,17 +3,292 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 //! ASN.1 primitives related to time types.
+//!
+//! With the default-on `std` feature disabled, this module takes `String`
+//! and `format!` from `alloc` and its writer types from `core2::io`, and
+//! [`UtcTime::now`], which needs the system clock, is left out. That
+//! configuration does not build: `bcder`'s `PrimitiveContent::write_encoded`
+//! is bound to `std::io::Write`, which the `core2::io` writer does not meet.
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
 
 use {
+    backend::{DateTime, Offset, TimeOps},
     bcder::{
-        decode::{Constructed, Malformed, Primitive, Source},
+        decode::{Constructed, Error, Malformed, Primitive, Source},
         encode::{PrimitiveContent, Values},
         Mode, Tag,
     },
-    chrono::{Datelike, TimeZone, Timelike},
-    std::{io::Write, ops::Deref, str::FromStr},
+    core::{ops::Deref, str::FromStr},
+};
+
+#[cfg(feature = "std")]
+use std::io::{Error as IoError, Write};
+
+#[cfg(not(feature = "std"))]
+use {
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core2::io::{Error as IoError, Write},
 };
 
+/// The datetime backend used by [`Time`], [`UtcTime`], and [`GeneralizedTime`].
+///
+/// By default this wraps `chrono`. Enabling the `backend-time` feature
+/// switches the representation to the `time` crate instead, for consumers
+/// who have already standardized on it. `chrono` stays a dependency of this
+/// crate either way, so enabling `backend-time` builds both datetime
+/// crates. Only the handful of operations these types actually need
+/// (component extraction, construction from year/month/day/hour/minute/
+/// second/nanosecond plus a UTC offset, and `now()`) are abstracted here;
+/// everything else in this module is written against [`DateTime`] and
+/// [`TimeOps`] and does not otherwise care which backend is active.
+#[cfg(not(feature = "backend-time"))]
+mod backend {
+    use chrono::{Datelike, TimeZone, Timelike};
+
+    pub type DateTime = chrono::DateTime<chrono::Utc>;
+    pub type Offset = chrono::FixedOffset;
+
+    pub trait TimeOps: Sized {
+        fn year(&self) -> i32;
+        fn month(&self) -> u32;
+        fn day(&self) -> u32;
+        fn hour(&self) -> u32;
+        fn minute(&self) -> u32;
+        fn second(&self) -> u32;
+        fn nanosecond(&self) -> u32;
+
+        fn utc_offset(seconds: i32) -> Option<Offset>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn from_ymd_hms_nano(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            minute: u32,
+            second: u32,
+            nanosecond: u32,
+            offset: Offset,
+        ) -> Option<Self>;
+
+        #[cfg(feature = "std")]
+        fn now() -> Self;
+    }
+
+    impl TimeOps for DateTime {
+        fn year(&self) -> i32 {
+            Datelike::year(self)
+        }
+
+        fn month(&self) -> u32 {
+            Datelike::month(self)
+        }
+
+        fn day(&self) -> u32 {
+            Datelike::day(self)
+        }
+
+        fn hour(&self) -> u32 {
+            Timelike::hour(self)
+        }
+
+        fn minute(&self) -> u32 {
+            Timelike::minute(self)
+        }
+
+        fn second(&self) -> u32 {
+            Timelike::second(self)
+        }
+
+        fn nanosecond(&self) -> u32 {
+            Timelike::nanosecond(self)
+        }
+
+        fn utc_offset(seconds: i32) -> Option<Offset> {
+            chrono::FixedOffset::east_opt(seconds)
+        }
+
+        fn from_ymd_hms_nano(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            minute: u32,
+            second: u32,
+            nanosecond: u32,
+            offset: Offset,
+        ) -> Option<Self> {
+            let naive = chrono::NaiveDate::from_ymd_opt(year, month, day)?
+                .and_hms_nano_opt(hour, minute, second, nanosecond)?;
+
+            match offset.from_local_datetime(&naive) {
+                chrono::LocalResult::Single(date) => Some(date.with_timezone(&chrono::Utc)),
+                _ => None,
+            }
+        }
+
+        #[cfg(feature = "std")]
+        fn now() -> Self {
+            chrono::Utc::now()
+        }
+    }
+}
+
+#[cfg(feature = "backend-time")]
+mod backend {
+    pub type DateTime = time::OffsetDateTime;
+    pub type Offset = time::UtcOffset;
+
+    // The `time` crate has no leap-second representation, unlike chrono's
+    // `nanosecond >= 1_000_000_000` convention. `TimeOps::from_ymd_hms_nano`
+    // below therefore fails (and the caller surfaces `Malformed`) for any
+    // value encoding a `:60` leap second; this backend cannot round-trip
+    // those timestamps the way the default chrono backend does.
+
+    pub trait TimeOps: Sized {
+        fn year(&self) -> i32;
+        fn month(&self) -> u32;
+        fn day(&self) -> u32;
+        fn hour(&self) -> u32;
+        fn minute(&self) -> u32;
+        fn second(&self) -> u32;
+        fn nanosecond(&self) -> u32;
+
+        fn utc_offset(seconds: i32) -> Option<Offset>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn from_ymd_hms_nano(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            minute: u32,
+            second: u32,
+            nanosecond: u32,
+            offset: Offset,
+        ) -> Option<Self>;
+
+        #[cfg(feature = "std")]
+        fn now() -> Self;
+    }
+
+    impl TimeOps for DateTime {
+        fn year(&self) -> i32 {
+            time::OffsetDateTime::year(*self)
+        }
+
+        fn month(&self) -> u32 {
+            u8::from(time::OffsetDateTime::month(*self)) as u32
+        }
+
+        fn day(&self) -> u32 {
+            time::OffsetDateTime::day(*self) as u32
+        }
+
+        fn hour(&self) -> u32 {
+            time::OffsetDateTime::hour(*self) as u32
+        }
+
+        fn minute(&self) -> u32 {
+            time::OffsetDateTime::minute(*self) as u32
+        }
+
+        fn second(&self) -> u32 {
+            time::OffsetDateTime::second(*self) as u32
+        }
+
+        fn nanosecond(&self) -> u32 {
+            time::OffsetDateTime::nanosecond(*self)
+        }
+
+        fn utc_offset(seconds: i32) -> Option<Offset> {
+            time::UtcOffset::from_whole_seconds(seconds).ok()
+        }
+
+        fn from_ymd_hms_nano(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            minute: u32,
+            second: u32,
+            nanosecond: u32,
+            offset: Offset,
+        ) -> Option<Self> {
+            let month = time::Month::try_from(u8::try_from(month).ok()?).ok()?;
+            let date = time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()?;
+            // `from_hms_nano` only accepts `nanosecond < 1_000_000_000`, so a
+            // leap-second value (see the module-level comment above) fails
+            // here and the caller observes `Malformed`, never a panic or a
+            // silently truncated timestamp.
+            let time = time::Time::from_hms_nano(
+                u8::try_from(hour).ok()?,
+                u8::try_from(minute).ok()?,
+                u8::try_from(second).ok()?,
+                nanosecond,
+            )
+            .ok()?;
+
+            Some(
+                date.with_time(time)
+                    .assume_offset(offset)
+                    .to_offset(time::UtcOffset::UTC),
+            )
+        }
+
+        #[cfg(feature = "std")]
+        fn now() -> Self {
+            time::OffsetDateTime::now_utc()
+        }
+    }
+}
+
+/// Splits the trailing timezone indicator off a BER-encoded time value.
+///
+/// Accepts the Zulu form (`Z`) as well as the differential forms
+/// `+hhmm`/`-hhmm` defined for `UTCTime` and `GeneralizedTime`. Returns the
+/// remaining date/time string along with the offset from UTC it encodes.
+fn split_timezone(data_str: &str) -> Result<(&str, Offset), Error> {
+    // All of BER/DER's time syntaxes are pure ASCII. Reject anything else
+    // up front: `from_utf8` only guarantees valid UTF-8, and a multi-byte
+    // character straddling one of the fixed byte offsets below would
+    // otherwise panic on the `&str` slicing instead of returning
+    // `Malformed`.
+    if !data_str.is_ascii() {
+        return Err(Malformed);
+    }
+
+    if let Some(prefix) = data_str.strip_suffix('Z') {
+        return Ok((prefix, DateTime::utc_offset(0).ok_or(Malformed)?));
+    }
+
+    if data_str.len() < "+hhmm".len() {
+        return Err(Malformed);
+    }
+
+    let (prefix, suffix) = data_str.split_at(data_str.len() - "+hhmm".len());
+
+    let sign = match suffix.as_bytes()[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return Err(Malformed),
+    };
+    let hours = i32::from_str(&suffix[1..3]).map_err(|_| Malformed)?;
+    let minutes = i32::from_str(&suffix[3..5]).map_err(|_| Malformed)?;
+    if hours > 23 || minutes > 59 {
+        return Err(Malformed);
+    }
+
+    let offset = DateTime::utc_offset(sign * (hours * 3600 + minutes * 60)).ok_or(Malformed)?;
+
+    Ok((prefix, offset))
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Time {
     UtcTime(UtcTime),
@@ -29,6 +304,24 @@ impl Time {
         })
     }
 
+    /// Like [`Self::take_from`], but requires DER's (X.690 §11.7/§11.8)
+    /// stricter canonical encoding of whichever of `UTCTime` or
+    /// `GeneralizedTime` is present.
+    ///
+    /// Use this when verifying a signature over DER-encoded content (e.g. a
+    /// certificate's `Validity.notBefore`/`notAfter`), where a non-canonical
+    /// re-encoding would indicate tampering rather than a merely lenient BER
+    /// producer.
+    pub fn take_from_der<S: Source>(cons: &mut Constructed<S>) -> Result<Self, S::Err> {
+        cons.take_primitive(|tag, prim| match tag {
+            Tag::UTC_TIME => Ok(Self::UtcTime(UtcTime::from_primitive_der(prim)?)),
+            Tag::GENERALIZED_TIME => Ok(Self::GeneralTime(GeneralizedTime::from_primitive_der(
+                prim,
+            )?)),
+            _ => Err(Malformed.into()),
+        })
+    }
+
     pub fn encode_ref(&self) -> impl Values + '_ {
         match self {
             Self::UtcTime(utc) => (Some(utc.encode()), None),
@@ -37,8 +330,8 @@ impl Time {
     }
 }
 
-impl AsRef<chrono::DateTime<chrono::Utc>> for Time {
-    fn as_ref(&self) -> &chrono::DateTime<chrono::Utc> {
+impl AsRef<DateTime> for Time {
+    fn as_ref(&self) -> &DateTime {
         match self {
             Self::UtcTime(dt) => dt.deref(),
             Self::GeneralTime(dt) => dt.deref(),
@@ -46,17 +339,17 @@ impl AsRef<chrono::DateTime<chrono::Utc>> for Time {
     }
 }
 
-impl From<chrono::DateTime<chrono::Utc>> for Time {
-    fn from(t: chrono::DateTime<chrono::Utc>) -> Self {
+impl From<DateTime> for Time {
+    fn from(t: DateTime) -> Self {
         Self::UtcTime(UtcTime(t))
     }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct GeneralizedTime(chrono::DateTime<chrono::Utc>);
+pub struct GeneralizedTime(DateTime);
 
 impl Deref for GeneralizedTime {
-    type Target = chrono::DateTime<chrono::Utc>;
+    type Target = DateTime;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -70,53 +363,148 @@ impl GeneralizedTime {
 
     pub fn from_primitive<S: Source>(prim: &mut Primitive<S>) -> Result<Self, S::Err> {
         let data = prim.take_all()?;
+        let data_str = core::str::from_utf8(&data).map_err(|_| Malformed)?;
+
+        Self::parse(data_str, false).map_err(Into::into)
+    }
+
+    /// Parses a `GeneralizedTime` requiring DER's (X.690 §11.7) stricter
+    /// canonical encoding on top of BER: the value must end in `Z`, a
+    /// fractional-seconds part must not carry trailing zeros or a bare
+    /// decimal point, and midnight must be encoded as `000000` rather than
+    /// `240000`.
+    ///
+    /// Use this over [`Self::from_primitive`] when verifying a signature
+    /// over DER-encoded content, where a non-canonical re-encoding would
+    /// indicate tampering rather than a merely lenient BER producer.
+    pub fn from_primitive_der<S: Source>(prim: &mut Primitive<S>) -> Result<Self, S::Err> {
+        let data = prim.take_all()?;
+        let data_str = core::str::from_utf8(&data).map_err(|_| Malformed)?;
+
+        Self::parse(data_str, true).map_err(Into::into)
+    }
+
+    fn parse(data_str: &str, der: bool) -> Result<Self, Error> {
+        if der && !data_str.ends_with('Z') {
+            return Err(Malformed);
+        }
+
+        // BER allows the Zulu form as well as the `+hhmm`/`-hhmm`
+        // differential forms; `split_timezone` normalizes either into an
+        // offset from UTC.
+        let (date_str, offset) = split_timezone(data_str)?;
 
         // Under the restrictions from [RFC 2459 Section 4.1.2.5.2](https://datatracker.ietf.org/doc/html/rfc2459#section-4.1.2.5.2),
         // granularity of GeneralizedTime is limited to one second.
         // In [RFC 3161](https://datatracker.ietf.org/doc/html/rfc3161#page-9),
         // GeneralizedTime can have fraction-of-time (The syntax is: YYYYMMDDhhmmss[.s...]Z)
-        // Thus data_len must be 15 (length of "YYYYMMDDHHMMSSZ") or more.
-        let mandatory_len = "YYYYMMDDHHMMSSZ".len();
-        let data_len = data.len();
-        // Timezone must be Zulu.
-        if data_len < mandatory_len || data[data_len - 1] != b'Z' {
-            return Err(Malformed.into());
+        // Thus date_str must be 14 (length of "YYYYMMDDHHMMSS") or more.
+        let mandatory_len = "YYYYMMDDHHMMSS".len();
+        if date_str.len() < mandatory_len {
+            return Err(Malformed);
         }
 
-        // skipping last Z
-        let date_str = std::str::from_utf8(&data[0..(data_len - 1)]).map_err(|_| Malformed)?;
+        let (whole, fraction) = date_str.split_at(mandatory_len);
 
-        let dt = if data_len == mandatory_len {
-            //YYYYMMDDHHMMSS
-            chrono::NaiveDateTime::parse_from_str(date_str, "%Y%m%d%H%M%S")
-                .map_err(|_| Malformed)?
-        } else {
-            chrono::NaiveDateTime::parse_from_str(
-                // padding to YYYYMMDDHHMMSS.sssssssss (9-digit fraction-of-second)
-                &format!("{:0<24}", date_str),
-                "%Y%m%d%H%M%S.%9f",
-            )
-            .map_err(|_| Malformed)?
+        // Anything after the mandatory 14-digit prefix must be a
+        // `.`-prefixed fractional-seconds suffix; trailing garbage (e.g. a
+        // truncated or bogus suffix) is malformed in both the lenient BER
+        // and strict DER paths.
+        if !fraction.is_empty() && !fraction.starts_with('.') {
+            return Err(Malformed);
+        }
+
+        if der {
+            if let Some(digits) = fraction.strip_prefix('.') {
+                if digits.is_empty() || digits.ends_with('0') {
+                    return Err(Malformed);
+                }
+            }
+
+            if &whole[8..14] == "240000" {
+                return Err(Malformed);
+            }
+        }
+
+        let year = i32::from_str(&whole[0..4]).map_err(|_| Malformed)?;
+        let month = u32::from_str(&whole[4..6]).map_err(|_| Malformed)?;
+        let day = u32::from_str(&whole[6..8]).map_err(|_| Malformed)?;
+        let hour = u32::from_str(&whole[8..10]).map_err(|_| Malformed)?;
+        let minute = u32::from_str(&whole[10..12]).map_err(|_| Malformed)?;
+        let second = u32::from_str(&whole[12..14]).map_err(|_| Malformed)?;
+
+        // 9-digit (nanosecond) fraction-of-second, right-padded with zeros.
+        let nanosecond = match fraction.strip_prefix('.') {
+            None => 0,
+            Some(digits)
+                if !digits.is_empty()
+                    && digits.len() <= 9
+                    && digits.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                u32::from_str(&format!("{:0<9}", digits)).map_err(|_| Malformed)?
+            }
+            Some(_) => return Err(Malformed),
         };
 
-        Ok(Self(chrono::DateTime::<chrono::Utc>::from_utc(
-            dt,
-            chrono::Utc,
-        )))
+        // ASN.1 time values may legitimately carry a seconds field of `60`
+        // during a UTC leap second. Following chrono's documented
+        // leap-second representation, this is passed down as the 59th
+        // second plus a 1_000_000_000 ns remainder.
+        let (second, nanosecond) = match second {
+            60 => (59, nanosecond + 1_000_000_000),
+            0..=59 => (second, nanosecond),
+            _ => return Err(Malformed),
+        };
+
+        DateTime::from_ymd_hms_nano(year, month, day, hour, minute, second, nanosecond, offset)
+            .map(Self)
+            .ok_or(Malformed)
     }
 }
 
 impl ToString for GeneralizedTime {
     fn to_string(&self) -> String {
-        format!(
-            "{:04}{:02}{:02}{:02}{:02}{:02}Z",
-            self.0.year(),
-            self.0.month(),
-            self.0.day(),
-            self.0.hour(),
-            self.0.minute(),
-            self.0.second()
-        )
+        // A nanosecond component of 1_000_000_000 or more is chrono's
+        // documented representation of a UTC leap second; unwind it back
+        // into a `60` seconds field instead of rolling over into the next
+        // minute.
+        let (second, nanosecond) = if TimeOps::nanosecond(&self.0) >= 1_000_000_000 {
+            (60, TimeOps::nanosecond(&self.0) - 1_000_000_000)
+        } else {
+            (TimeOps::second(&self.0), TimeOps::nanosecond(&self.0))
+        };
+
+        if nanosecond == 0 {
+            format!(
+                "{:04}{:02}{:02}{:02}{:02}{:02}Z",
+                TimeOps::year(&self.0),
+                TimeOps::month(&self.0),
+                TimeOps::day(&self.0),
+                TimeOps::hour(&self.0),
+                TimeOps::minute(&self.0),
+                second
+            )
+        } else {
+            // Fraction-of-second, per the RFC 3161 syntax
+            // `YYYYMMDDhhmmss[.s...]Z`. Trailing zeros are dropped, as DER
+            // requires, so a value decoded via `from_primitive_der`
+            // round-trips to the same bytes. A non-canonical BER value
+            // accepted by `from_primitive` (e.g. `.10` or a `+hhmm` offset)
+            // re-encodes in this canonical form instead.
+            let fraction = format!("{:09}", nanosecond);
+            let fraction = fraction.trim_end_matches('0');
+
+            format!(
+                "{:04}{:02}{:02}{:02}{:02}{:02}.{}Z",
+                TimeOps::year(&self.0),
+                TimeOps::month(&self.0),
+                TimeOps::day(&self.0),
+                TimeOps::hour(&self.0),
+                TimeOps::minute(&self.0),
+                second,
+                fraction
+            )
+        }
     }
 }
 
@@ -127,18 +515,19 @@ impl PrimitiveContent for GeneralizedTime {
         self.to_string().len()
     }
 
-    fn write_encoded<W: Write>(&self, _: Mode, target: &mut W) -> Result<(), std::io::Error> {
+    fn write_encoded<W: Write>(&self, _: Mode, target: &mut W) -> Result<(), IoError> {
         target.write_all(self.to_string().as_bytes())
     }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct UtcTime(chrono::DateTime<chrono::Utc>);
+pub struct UtcTime(DateTime);
 
 impl UtcTime {
     /// Obtain a new instance with now as the time.
+    #[cfg(feature = "std")]
     pub fn now() -> Self {
-        Self(chrono::Utc::now())
+        Self(DateTime::now())
     }
 
     pub fn take_from<S: Source>(cons: &mut Constructed<S>) -> Result<Self, S::Err> {
@@ -147,59 +536,90 @@ impl UtcTime {
 
     pub fn from_primitive<S: Source>(prim: &mut Primitive<S>) -> Result<Self, S::Err> {
         let data = prim.take_all()?;
+        let data_str = core::str::from_utf8(&data).map_err(|_| Malformed)?;
+
+        Self::parse(data_str, false).map_err(Into::into)
+    }
+
+    /// Parses a `UTCTime` requiring DER's (X.690 §11.8) stricter canonical
+    /// encoding on top of BER: the value must end in `Z` and midnight must
+    /// be encoded as `000000` rather than `240000`.
+    ///
+    /// Use this over [`Self::from_primitive`] when verifying a signature
+    /// over DER-encoded content, where a non-canonical re-encoding would
+    /// indicate tampering rather than a merely lenient BER producer.
+    pub fn from_primitive_der<S: Source>(prim: &mut Primitive<S>) -> Result<Self, S::Err> {
+        let data = prim.take_all()?;
+        let data_str = core::str::from_utf8(&data).map_err(|_| Malformed)?;
+
+        Self::parse(data_str, true).map_err(Into::into)
+    }
 
-        if data.len() != "YYMMDDHHMMSSZ".len() {
-            return Err(Malformed.into());
+    fn parse(data_str: &str, der: bool) -> Result<Self, Error> {
+        if der && !data_str.ends_with('Z') {
+            return Err(Malformed);
         }
 
-        let year = i32::from_str(std::str::from_utf8(&data[0..2]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
+        // BER allows the Zulu form as well as the `+hhmm`/`-hhmm`
+        // differential forms; `split_timezone` normalizes either into an
+        // offset from UTC.
+        let (date_str, offset) = split_timezone(data_str)?;
 
-        let year = if year >= 50 { year + 1900 } else { year + 2000 };
+        if date_str.len() != "YYMMDDHHMMSS".len() {
+            return Err(Malformed);
+        }
 
-        let month = u32::from_str(std::str::from_utf8(&data[2..4]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
-        let day = u32::from_str(std::str::from_utf8(&data[4..6]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
-        let hour = u32::from_str(std::str::from_utf8(&data[6..8]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
-        let minute = u32::from_str(std::str::from_utf8(&data[8..10]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
-        let second = u32::from_str(std::str::from_utf8(&data[10..12]).map_err(|_| Malformed)?)
-            .map_err(|_| Malformed)?;
-
-        if data[12] != b'Z' {
-            return Err(Malformed.into());
-        }
-
-        if let chrono::LocalResult::Single(dt) = chrono::Utc.ymd_opt(year, month, day) {
-            if let Some(dt) = dt.and_hms_opt(hour, minute, second) {
-                Ok(Self(dt))
-            } else {
-                Err(Malformed.into())
-            }
-        } else {
-            Err(Malformed.into())
+        if der && &date_str[6..12] == "240000" {
+            return Err(Malformed);
         }
+
+        let year = i32::from_str(&date_str[0..2]).map_err(|_| Malformed)?;
+
+        let year = if year >= 50 { year + 1900 } else { year + 2000 };
+
+        let month = u32::from_str(&date_str[2..4]).map_err(|_| Malformed)?;
+        let day = u32::from_str(&date_str[4..6]).map_err(|_| Malformed)?;
+        let hour = u32::from_str(&date_str[6..8]).map_err(|_| Malformed)?;
+        let minute = u32::from_str(&date_str[8..10]).map_err(|_| Malformed)?;
+        let second = u32::from_str(&date_str[10..12]).map_err(|_| Malformed)?;
+
+        // See the equivalent leap-second handling in `GeneralizedTime::parse`.
+        let (second, nanosecond) = match second {
+            60 => (59, 1_000_000_000),
+            0..=59 => (second, 0),
+            _ => return Err(Malformed),
+        };
+
+        DateTime::from_ymd_hms_nano(year, month, day, hour, minute, second, nanosecond, offset)
+            .map(Self)
+            .ok_or(Malformed)
     }
 }
 
 impl ToString for UtcTime {
     fn to_string(&self) -> String {
+        // See the equivalent leap-second handling in
+        // `GeneralizedTime::to_string`.
+        let second = if TimeOps::nanosecond(&self.0) >= 1_000_000_000 {
+            60
+        } else {
+            TimeOps::second(&self.0)
+        };
+
         format!(
             "{:02}{:02}{:02}{:02}{:02}{:02}Z",
-            self.0.year() % 100,
-            self.0.month(),
-            self.0.day(),
-            self.0.hour(),
-            self.0.minute(),
-            self.0.second()
+            TimeOps::year(&self.0) % 100,
+            TimeOps::month(&self.0),
+            TimeOps::day(&self.0),
+            TimeOps::hour(&self.0),
+            TimeOps::minute(&self.0),
+            second
         )
     }
 }
 
 impl Deref for UtcTime {
-    type Target = chrono::DateTime<chrono::Utc>;
+    type Target = DateTime;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -213,7 +633,86 @@ impl PrimitiveContent for UtcTime {
         self.to_string().len()
     }
 
-    fn write_encoded<W: Write>(&self, _: Mode, target: &mut W) -> Result<(), std::io::Error> {
+    fn write_encoded<W: Write>(&self, _: Mode, target: &mut W) -> Result<(), IoError> {
         target.write_all(self.to_string().as_bytes())
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_timezone_rejects_non_ascii_instead_of_panicking() {
+        // Reproduces a byte-offset/char-boundary mismatch: `é` is a 2-byte
+        // UTF-8 character, so the fixed `+hhmm`-width split below would
+        // otherwise land inside it instead of on a char boundary.
+        assert!(split_timezone("abcdéwxyz").is_err());
+    }
+
+    #[test]
+    fn general_time_offset_is_normalized_to_utc() {
+        let parsed = GeneralizedTime::parse("20230101120000+0100", false).unwrap();
+        assert_eq!(
+            (
+                TimeOps::year(&parsed.0),
+                TimeOps::month(&parsed.0),
+                TimeOps::day(&parsed.0),
+                TimeOps::hour(&parsed.0)
+            ),
+            (2023, 1, 1, 11)
+        );
+    }
+
+    #[test]
+    fn general_time_der_rejects_trailing_zero_fraction() {
+        assert!(GeneralizedTime::parse("20230101000000.10Z", true).is_err());
+    }
+
+    #[test]
+    fn general_time_lenient_accepts_trailing_zero_fraction() {
+        let parsed = GeneralizedTime::parse("20230101000000.10Z", false).unwrap();
+        assert_eq!(TimeOps::nanosecond(&parsed.0), 100_000_000);
+    }
+
+    #[test]
+    fn general_time_lenient_rejects_trailing_garbage() {
+        assert!(GeneralizedTime::parse("20230101000000GARBAGEZ", false).is_err());
+    }
+
+    // The `time`-backed `TimeOps::from_ymd_hms_nano` rejects leap seconds
+    // outright (see the module-level comment on `mod backend`), so these two
+    // only hold for the default chrono backend.
+    #[test]
+    #[cfg(not(feature = "backend-time"))]
+    fn general_time_round_trips_leap_second() {
+        let parsed = GeneralizedTime::parse("20230630235960Z", false).unwrap();
+        assert_eq!(parsed.to_string(), "20230630235960Z");
+    }
+
+    #[test]
+    #[cfg(not(feature = "backend-time"))]
+    fn utc_time_round_trips_leap_second() {
+        let parsed = UtcTime::parse("230630235960Z", false).unwrap();
+        assert_eq!(parsed.to_string(), "230630235960Z");
+    }
+
+    fn der_generalized_time(content: &str) -> std::vec::Vec<u8> {
+        let mut data = std::vec![0x18, content.len() as u8];
+        data.extend_from_slice(content.as_bytes());
+        data
+    }
+
+    #[test]
+    fn time_take_from_der_accepts_canonical_generalized_time() {
+        let data = der_generalized_time("20230101000000Z");
+        let parsed = Constructed::decode(data.as_slice(), Mode::Der, Time::take_from_der).unwrap();
+        assert_eq!(TimeOps::year(parsed.as_ref()), 2023);
+    }
+
+    #[test]
+    fn time_take_from_der_rejects_trailing_zero_fraction() {
+        let data = der_generalized_time("20230101000000.10Z");
+        assert!(Constructed::decode(data.as_slice(), Mode::Der, Time::take_from_der).is_err());
+    }
+}